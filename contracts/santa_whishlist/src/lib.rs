@@ -1,14 +1,46 @@
 #![no_std]
-use soroban_sdk::{Address, Env, String, Vec, contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error};
+use soroban_sdk::{Address, Env, String, Vec, contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error, token};
+
+mod store;
+use store::{InstanceStore, PersistentStore, Store};
+
+// A list name longer than this is rejected by create_list.
+const MAX_LIST_NAME_LEN: u32 = 32;
 
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
-    Wishes(Address),
-    NextId(Address),
+    Wishes(Address, String),
+    NextId(Address, String),
+    Lists(Address),
+    Escrow(Address, String, u32),
     ChristmasDeadline,
-    Admin,
-    NaughtyList
+    Committee,
+    Threshold,
+    NaughtyVotes(Address),
+    NaughtyVote(Address, Address, u32),
+    NaughtyEpoch(Address),
+    VoterWeight(Address),
+    NaughtyThreshold
+}
+
+// A condition that must hold before escrowed funds can be released to the kid.
+#[derive(Clone)]
+#[contracttype]
+pub enum Witness {
+    // Satisfied once the ledger timestamp reaches this value (e.g. Christmas morning).
+    Timestamp(u64),
+    // Satisfied by the Santa committee co-signing the `mark_fulfilled` call.
+    Signature,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Escrow {
+    pub funder: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub witnesses: Vec<Witness>,
 }
 
 #[derive(Clone)]
@@ -32,11 +64,74 @@ pub struct WishFulfilledEvent {
     pub wish_id: u32,
 }
 
+#[contractevent]
+pub struct ListCreatedEvent {
+    pub user: Address,
+    pub list: String,
+}
+
+#[contractevent]
+pub struct ListDeletedEvent {
+    pub user: Address,
+    pub list: String,
+}
+
+#[contractevent]
+pub struct EscrowFundedEvent {
+    pub user: Address,
+    pub wish_id: u32,
+    pub funder: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct EscrowReleasedEvent {
+    pub user: Address,
+    pub wish_id: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct EscrowReclaimedEvent {
+    pub user: Address,
+    pub wish_id: u32,
+    pub funder: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct NaughtyThresholdCrossedEvent {
+    pub target: Address,
+    pub score: i128,
+}
+
+#[contractevent]
+pub struct PardonedEvent {
+    pub target: Address,
+}
+
 #[contracterror]
 pub enum ContractError {
   WishNotFound = 1,
   TooLateToChange = 2,
-  YouAreNaughty = 3
+  YouAreNaughty = 3,
+  ListNotFound = 4,
+  ListAlreadyExists = 5,
+  ListNameTooLong = 6,
+  EscrowNotFound = 7,
+  EscrowAlreadyExists = 8,
+  NotEscrowFunder = 9,
+  WitnessesNotSatisfied = 10,
+  WishAlreadyFulfilled = 11,
+  TooEarlyToReclaim = 12,
+  NotCommitteeMember = 13,
+  DuplicateApprover = 14,
+  ThresholdNotMet = 15,
+  InvalidThreshold = 16,
+  SignerAlreadyExists = 17,
+  NotRegisteredVoter = 18,
+  InvalidVoteWeight = 19,
+  ListHasActiveEscrows = 20,
 }
 
 #[contract]
@@ -45,11 +140,11 @@ pub struct SeasonalWishlist;
 // Helpers to manage TTL (Time To Live)
 // We bump the lifespan of data every time it is accessed.
 fn bump_persistent_ttl(env: &Env, key: &DataKey) {
-    env.storage().persistent().extend_ttl(key, 2_000, 5_000); // If < 2000 ledgers, bump to 5000
+    PersistentStore::new(env).bump_ttl(key);
 }
 
 fn bump_instance_ttl(env: &Env) {
-    env.storage().instance().extend_ttl(2_000, 5_000);
+    InstanceStore::new(env).bump();
 }
 
 fn fail(env: &Env, e: ContractError) -> ! {
@@ -61,69 +156,354 @@ fn ensure_not_christmas(env: &Env) {
     let current_time = env.ledger().timestamp();
     // The deadline timestamp (Unix seconds).
     // Example: Dec 25, 2025 00:00:00 UTC
-    let christmas_deadline = env.storage().instance().get::<_, u64>(&DataKey::ChristmasDeadline).unwrap_or(1_766_620_800);
+    let christmas_deadline = InstanceStore::new(env).get::<u64>(&DataKey::ChristmasDeadline).unwrap_or(1_766_620_800);
     if current_time >= christmas_deadline {
         // It is Christmas (or later), we cannot accept changes!
         fail(&env, ContractError::TooLateToChange);
     }
 }
 
-fn check_naughty_list(env: &Env, user: &Address) {
-    // Fetch the list. If it doesn't exist, default to an empty list (everyone is nice!)
-    let naughty_list: Vec<Address> = env.storage().instance()
-        .get(&DataKey::NaughtyList)
-        .unwrap_or(Vec::new(env));
+// A user's aggregate naughty score, accumulated from `report_naughty` votes.
+fn naughty_score_of(env: &Env, target: &Address) -> i128 {
+    PersistentStore::new(env).get(&DataKey::NaughtyVotes(target.clone())).unwrap_or(0)
+}
+
+// The target's current voting epoch. `pardon` advances this, which orphans every
+// `NaughtyVote` recorded under the previous epoch so `report_naughty` can no longer
+// see (and subtract) contributions that predate the pardon.
+fn naughty_epoch_of(env: &Env, target: &Address) -> u32 {
+    PersistentStore::new(env).get(&DataKey::NaughtyEpoch(target.clone())).unwrap_or(0)
+}
+
+fn get_naughty_threshold(env: &Env) -> i128 {
+    InstanceStore::new(env).get::<i128>(&DataKey::NaughtyThreshold).unwrap_or(100)
+}
 
-    // The "Check it Twice" logic
-    if naughty_list.contains(user) {
+fn check_naughty_list(env: &Env, user: &Address) {
+    // The "Check it Twice" logic: fail only once the community's votes cross the threshold.
+    if naughty_score_of(env, user) >= get_naughty_threshold(env) {
         // Stop execution immediately
         fail(env, ContractError::YouAreNaughty);
     }
 }
+
+fn get_committee(env: &Env) -> Vec<Address> {
+    InstanceStore::new(env).get(&DataKey::Committee).unwrap_or_else(|| Vec::new(env))
+}
+
+fn get_threshold(env: &Env) -> u32 {
+    InstanceStore::new(env).get(&DataKey::Threshold).unwrap_or(1)
+}
+
+// Require that at least `threshold` distinct committee members are among the approvers,
+// each proving it with their own `require_auth()`. With threshold == 1 this behaves just
+// like the old single-admin gate.
+fn require_committee_approval(env: &Env, approvers: &Vec<Address>) {
+    let committee = get_committee(env);
+    let threshold = get_threshold(env);
+
+    let mut seen: Vec<Address> = Vec::new(env);
+    for approver in approvers.iter() {
+        if !committee.contains(&approver) {
+            fail(env, ContractError::NotCommitteeMember);
+        }
+        if seen.contains(&approver) {
+            fail(env, ContractError::DuplicateApprover);
+        }
+        approver.require_auth();
+        seen.push_back(approver);
+    }
+
+    if seen.len() < threshold {
+        fail(env, ContractError::ThresholdNotMet);
+    }
+}
+
+// Every user starts with a single "Main" list until they create more.
+fn default_lists(env: &Env) -> Vec<String> {
+    Vec::from_array(env, [String::from_str(env, "Main")])
+}
+
+fn get_lists_or_default(env: &Env, user: &Address) -> Vec<String> {
+    InstanceStore::new(env).get(&DataKey::Lists(user.clone())).unwrap_or_else(|| default_lists(env))
+}
+
+fn ensure_list_exists(env: &Env, user: &Address, list: &String) {
+    if !get_lists_or_default(env, user).contains(list) {
+        fail(env, ContractError::ListNotFound);
+    }
+}
+
+// True if any wish in (user, list) still has funds locked against it. Used to block
+// deleting a list out from under an escrow, which would otherwise let a recreated list
+// reuse the same wish ids and inherit a stranger's stale escrow.
+fn list_has_active_escrow(env: &Env, user: &Address, list: &String) -> bool {
+    let store = PersistentStore::new(env);
+    let wishes: Vec<Wish> = store.get(&DataKey::Wishes(user.clone(), list.clone())).unwrap_or_else(|| Vec::new(env));
+    for wish in wishes.iter() {
+        if store.has(&DataKey::Escrow(user.clone(), list.clone(), wish.id)) {
+            return true;
+        }
+    }
+    false
+}
+
+// Find a wish by id within a list's wishes, returning its index.
+fn find_wish_index(wishes: &Vec<Wish>, wish_id: u32) -> Option<u32> {
+    for i in 0..wishes.len() {
+        if wishes.get(i).unwrap().id == wish_id {
+            return Some(i);
+        }
+    }
+    None
+}
+
+// All witnesses must hold for escrowed funds to release. The `Signature` witness is
+// satisfied by construction: `mark_fulfilled` already required committee approval to get here.
+fn witnesses_satisfied(env: &Env, witnesses: &Vec<Witness>) -> bool {
+    let current_time = env.ledger().timestamp();
+    for witness in witnesses.iter() {
+        match witness {
+            Witness::Timestamp(ts) => if current_time < ts { return false; },
+            Witness::Signature => {}
+        }
+    }
+    true
+}
+
 #[contractimpl]
 impl SeasonalWishlist {
-    pub fn __constructor(env: Env, admin: Address, christmas_deadline: u64, naughty_list: Vec<Address>) {
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage().instance().set(&DataKey::ChristmasDeadline, &christmas_deadline);
-        env.storage().instance().set(&DataKey::NaughtyList, &naughty_list);
+    pub fn __constructor(env: Env, committee: Vec<Address>, threshold: u32, christmas_deadline: u64, naughty_threshold: i128) {
+        if threshold == 0 || threshold > committee.len() {
+            fail(&env, ContractError::InvalidThreshold);
+        }
+
+        let store = InstanceStore::new(&env);
+        store.set(&DataKey::Committee, &committee);
+        store.set(&DataKey::Threshold, &threshold);
+        store.set(&DataKey::ChristmasDeadline, &christmas_deadline);
+        store.set(&DataKey::NaughtyThreshold, &naughty_threshold);
+    }
+
+    pub fn set_christmas_deadline(env: Env, approvers: Vec<Address>, christmas_deadline: u64) {
+      require_committee_approval(&env, &approvers);
+
+      InstanceStore::new(&env).set(&DataKey::ChristmasDeadline, &christmas_deadline);
+      bump_instance_ttl(&env);
+    }
+
+    /// Committee function: add an elf to the Santa committee.
+    pub fn add_signer(env: Env, approvers: Vec<Address>, new_signer: Address) {
+        require_committee_approval(&env, &approvers);
+
+        let mut committee = get_committee(&env);
+        if committee.contains(&new_signer) {
+            fail(&env, ContractError::SignerAlreadyExists);
+        }
+        committee.push_back(new_signer);
+        InstanceStore::new(&env).set(&DataKey::Committee, &committee);
+        bump_instance_ttl(&env);
+    }
+
+    /// Committee function: remove an elf from the committee, as long as enough remain to meet the threshold.
+    pub fn remove_signer(env: Env, approvers: Vec<Address>, signer: Address) {
+        require_committee_approval(&env, &approvers);
+
+        let mut committee = get_committee(&env);
+        let mut found = false;
+        for i in 0..committee.len() {
+            if committee.get(i).unwrap() == signer {
+                committee.remove(i);
+                found = true;
+                break;
+            }
+        }
+        if !found { fail(&env, ContractError::NotCommitteeMember); }
+
+        if get_threshold(&env) > committee.len() {
+            fail(&env, ContractError::InvalidThreshold);
+        }
+
+        InstanceStore::new(&env).set(&DataKey::Committee, &committee);
+        bump_instance_ttl(&env);
+    }
+
+    /// Committee function: change how many signers must approve.
+    pub fn set_threshold(env: Env, approvers: Vec<Address>, threshold: u32) {
+        require_committee_approval(&env, &approvers);
+
+        if threshold == 0 || threshold > get_committee(&env).len() {
+            fail(&env, ContractError::InvalidThreshold);
+        }
+
+        InstanceStore::new(&env).set(&DataKey::Threshold, &threshold);
+        bump_instance_ttl(&env);
+    }
+
+    /// Committee function: set how many naughty points it takes to land on the naughty list.
+    pub fn set_naughty_threshold(env: Env, approvers: Vec<Address>, naughty_threshold: i128) {
+        require_committee_approval(&env, &approvers);
+
+        InstanceStore::new(&env).set(&DataKey::NaughtyThreshold, &naughty_threshold);
+        bump_instance_ttl(&env);
+    }
+
+    /// Committee function: register (or update) a voter's stake in the naughty-points system.
+    pub fn set_voter_weight(env: Env, approvers: Vec<Address>, voter: Address, weight: i128) {
+        require_committee_approval(&env, &approvers);
+
+        if weight < 0 {
+            fail(&env, ContractError::InvalidVoteWeight);
+        }
+
+        let key = DataKey::VoterWeight(voter);
+        PersistentStore::new(&env).set(&key, &weight);
+        bump_persistent_ttl(&env, &key);
+    }
+
+    /// Registered voter's function: cast naughty points against a target. `weight` can't
+    /// exceed the voter's registered stake, and re-voting replaces rather than stacks their
+    /// prior contribution, so one voter can't inflate a target's score past their own weight.
+    pub fn report_naughty(env: Env, voter: Address, target: Address, weight: i128) {
+        voter.require_auth();
+
+        let store = PersistentStore::new(&env);
+        let registered_weight: i128 = store.get(&DataKey::VoterWeight(voter.clone())).unwrap_or(0);
+        if registered_weight <= 0 {
+            fail(&env, ContractError::NotRegisteredVoter);
+        }
+        if weight <= 0 || weight > registered_weight {
+            fail(&env, ContractError::InvalidVoteWeight);
+        }
+
+        let epoch = naughty_epoch_of(&env, &target);
+        let vote_key = DataKey::NaughtyVote(voter, target.clone(), epoch);
+        let previous_contribution: i128 = store.get(&vote_key).unwrap_or(0);
+        store.set(&vote_key, &weight);
+        bump_persistent_ttl(&env, &vote_key);
+
+        let votes_key = DataKey::NaughtyVotes(target.clone());
+        let previous_score: i128 = store.get(&votes_key).unwrap_or(0);
+        let updated_score = previous_score - previous_contribution + weight;
+        store.set(&votes_key, &updated_score);
+        bump_persistent_ttl(&env, &votes_key);
+
+        let naughty_threshold = get_naughty_threshold(&env);
+        if previous_score < naughty_threshold && updated_score >= naughty_threshold {
+            NaughtyThresholdCrossedEvent { target, score: updated_score }.publish(&env);
+        }
     }
 
-    pub fn set_christmas_deadline(env: &Env, christmas_deadline: u64) {
-      let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Santa missing");
-      admin.require_auth();
-      
-      env.storage().instance().set(&DataKey::ChristmasDeadline, &christmas_deadline);
+    /// Committee function: clear a target's naughty score. Also advances their voting
+    /// epoch, so pre-pardon `NaughtyVote` contributions are left behind instead of being
+    /// subtracted from (and corrupting) the freshly-zeroed score on the next vote.
+    pub fn pardon(env: Env, approvers: Vec<Address>, target: Address) {
+        require_committee_approval(&env, &approvers);
+
+        let store = PersistentStore::new(&env);
+        store.remove(&DataKey::NaughtyVotes(target.clone()));
+
+        let epoch_key = DataKey::NaughtyEpoch(target.clone());
+        let next_epoch = naughty_epoch_of(&env, &target) + 1;
+        store.set(&epoch_key, &next_epoch);
+        bump_persistent_ttl(&env, &epoch_key);
+
+        PardonedEvent { target }.publish(&env);
+    }
+
+    /// View function: a target's current aggregate naughty score.
+    pub fn naughty_score(env: Env, target: Address) -> i128 {
+        naughty_score_of(&env, &target)
+    }
+
+    /// Kid's function: Create a new named list (e.g. "Toys", "Books", "For Mom").
+    pub fn create_list(env: Env, user: Address, list: String) {
+        user.require_auth();
+
+        if list.len() > MAX_LIST_NAME_LEN {
+            fail(&env, ContractError::ListNameTooLong);
+        }
+
+        let mut lists = get_lists_or_default(&env, &user);
+        if lists.contains(&list) {
+            fail(&env, ContractError::ListAlreadyExists);
+        }
+
+        lists.push_back(list.clone());
+        InstanceStore::new(&env).set(&DataKey::Lists(user.clone()), &lists);
+        bump_instance_ttl(&env);
+
+        ListCreatedEvent { user, list }.publish(&env);
     }
 
-    /// Kid's function: Add a wish.
-    pub fn add_wish(env: Env, user: Address, text: String) -> u32 {
+    /// Kid's function: Delete a named list and everything on it.
+    pub fn delete_list(env: Env, user: Address, list: String) {
+        user.require_auth();
+
+        let mut lists = get_lists_or_default(&env, &user);
+
+        // Find the list by name so we know its index to remove.
+        let mut found = false;
+        for i in 0..lists.len() {
+            if lists.get(i).unwrap() == list {
+                lists.remove(i);
+                found = true;
+                break;
+            }
+        }
+        if !found { fail(&env, ContractError::ListNotFound); }
+
+        if list_has_active_escrow(&env, &user, &list) {
+            fail(&env, ContractError::ListHasActiveEscrows);
+        }
+
+        InstanceStore::new(&env).set(&DataKey::Lists(user.clone()), &lists);
+        bump_instance_ttl(&env);
+
+        let store = PersistentStore::new(&env);
+        store.remove(&DataKey::Wishes(user.clone(), list.clone()));
+        store.remove(&DataKey::NextId(user.clone(), list.clone()));
+
+        ListDeletedEvent { user, list }.publish(&env);
+    }
+
+    /// View function: see all of a user's list names.
+    pub fn get_lists(env: Env, user: Address) -> Vec<String> {
+        get_lists_or_default(&env, &user)
+    }
+
+    /// Kid's function: Add a wish to one of their lists.
+    pub fn add_wish(env: Env, user: Address, list: String, text: String) -> u32 {
         ensure_not_christmas(&env);
         // AUTH: Ensure the transaction signer is actually the user
         user.require_auth();
 
         check_naughty_list(&env, &user);
+        ensure_list_exists(&env, &user, &list);
 
-        // 1. Generate ID
-        let id_key = DataKey::NextId(user.clone());
-        let mut next_id: u32 = env.storage().persistent().get(&id_key).unwrap_or(1);
+        let store = PersistentStore::new(&env);
+
+        // 1. Generate ID (scoped to this list, so IDs stay stable within it)
+        let id_key = DataKey::NextId(user.clone(), list.clone());
+        let mut next_id: u32 = store.get(&id_key).unwrap_or(1);
         let current_id = next_id;
         next_id += 1;
-        env.storage().persistent().set(&id_key, &next_id);
+        store.set(&id_key, &next_id);
 
         // 2. Load existing wishes
-        let wish_key = DataKey::Wishes(user.clone());
-        let mut wishes: Vec<Wish> = env.storage().persistent().get(&wish_key).unwrap_or_else(|| Vec::new(&env));
+        let wish_key = DataKey::Wishes(user.clone(), list.clone());
+        let mut wishes: Vec<Wish> = store.get(&wish_key).unwrap_or_else(|| Vec::new(&env));
 
         // 3. Add new wish
         wishes.push_back(Wish {
             id: current_id,
             text,
             created_at_ledger: env.ledger().sequence(),
-            fulfilled: false, 
+            fulfilled: false,
         });
 
         // 4. Save and Bump TTL
-        env.storage().persistent().set(&wish_key, &wishes);
+        store.set(&wish_key, &wishes);
         bump_persistent_ttl(&env, &wish_key);
         bump_instance_ttl(&env);
 
@@ -136,44 +516,288 @@ impl SeasonalWishlist {
         current_id
     }
 
-    /// Santa's function: Mark a wish as fulfilled.
-    pub fn mark_fulfilled(env: Env, user: Address, wish_id: u32) {
-        // AUTH: Get the admin address and require THEIR signature
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Santa missing");
-        admin.require_auth();
+    /// Committee function: mark a wish as fulfilled. If it has escrowed funds, they are
+    /// released to the user only once every witness condition on the escrow holds.
+    pub fn mark_fulfilled(env: Env, approvers: Vec<Address>, user: Address, list: String, wish_id: u32) {
+        require_committee_approval(&env, &approvers);
 
-        let wish_key = DataKey::Wishes(user.clone());
-        let mut wishes: Vec<Wish> = env.storage().persistent().get(&wish_key).unwrap_or_else(|| Vec::new(&env));
+        let store = PersistentStore::new(&env);
+        let wish_key = DataKey::Wishes(user.clone(), list.clone());
+        let mut wishes: Vec<Wish> = store.get(&wish_key).unwrap_or_else(|| Vec::new(&env));
 
-        // Iterate to find the wish and update it
-        let mut found = false;
-        for i in 0..wishes.len() {
-            let mut wish = wishes.get(i).unwrap();
-            if wish.id == wish_id {
-                wish.fulfilled = true;
-                wishes.set(i, wish);
-                found = true;
-                break;
+        let index = find_wish_index(&wishes, wish_id);
+        let Some(index) = index else { fail(&env, ContractError::WishNotFound); };
+        let mut wish = wishes.get(index).unwrap();
+        wish.fulfilled = true;
+        wishes.set(index, wish);
+
+        store.set(&wish_key, &wishes);
+        bump_persistent_ttl(&env, &wish_key);
+
+        let escrow_key = DataKey::Escrow(user.clone(), list, wish_id);
+        if let Some(escrow) = store.get::<Escrow>(&escrow_key) {
+            if !witnesses_satisfied(&env, &escrow.witnesses) {
+                fail(&env, ContractError::WitnessesNotSatisfied);
             }
+
+            token::Client::new(&env, &escrow.token).transfer(
+                &env.current_contract_address(),
+                &user,
+                &escrow.amount,
+            );
+            store.remove(&escrow_key);
+
+            EscrowReleasedEvent {
+              user: user.clone(),
+              wish_id,
+              amount: escrow.amount,
+            }.publish(&env);
         }
-        
-        if !found { fail(&env, ContractError::WishNotFound); }
 
-        env.storage().persistent().set(&wish_key, &wishes);
-        bump_persistent_ttl(&env, &wish_key);
-        
         WishFulfilledEvent {
           user,
           wish_id
         }.publish(&env);
     }
-    
+
+    /// Gift-giver's function: Lock tokens against a wish, released once its witnesses hold.
+    pub fn fund_wish(env: Env, funder: Address, user: Address, list: String, wish_id: u32, token: Address, amount: i128, witnesses: Vec<Witness>) {
+        funder.require_auth();
+
+        ensure_list_exists(&env, &user, &list);
+
+        let store = PersistentStore::new(&env);
+        let wish_key = DataKey::Wishes(user.clone(), list.clone());
+        let wishes: Vec<Wish> = store.get(&wish_key).unwrap_or_else(|| Vec::new(&env));
+        match find_wish_index(&wishes, wish_id) {
+            None => fail(&env, ContractError::WishNotFound),
+            Some(index) if wishes.get(index).unwrap().fulfilled => {
+                fail(&env, ContractError::WishAlreadyFulfilled)
+            }
+            Some(_) => {}
+        }
+
+        let escrow_key = DataKey::Escrow(user.clone(), list, wish_id);
+        if store.has(&escrow_key) {
+            fail(&env, ContractError::EscrowAlreadyExists);
+        }
+
+        token::Client::new(&env, &token).transfer(&funder, &env.current_contract_address(), &amount);
+
+        store.set(&escrow_key, &Escrow {
+            funder: funder.clone(),
+            token,
+            amount,
+            witnesses,
+        });
+        bump_persistent_ttl(&env, &escrow_key);
+
+        EscrowFundedEvent {
+          user,
+          wish_id,
+          funder,
+          amount,
+        }.publish(&env);
+    }
+
+    /// Gift-giver's function: Recover escrowed funds if Christmas passes unfulfilled.
+    pub fn reclaim_escrow(env: Env, funder: Address, user: Address, list: String, wish_id: u32) {
+        funder.require_auth();
+
+        let christmas_deadline = InstanceStore::new(&env).get::<u64>(&DataKey::ChristmasDeadline).unwrap_or(1_766_620_800);
+        if env.ledger().timestamp() < christmas_deadline {
+            fail(&env, ContractError::TooEarlyToReclaim);
+        }
+
+        let store = PersistentStore::new(&env);
+        let wish_key = DataKey::Wishes(user.clone(), list.clone());
+        let wishes: Vec<Wish> = store.get(&wish_key).unwrap_or_else(|| Vec::new(&env));
+        if let Some(index) = find_wish_index(&wishes, wish_id) {
+            if wishes.get(index).unwrap().fulfilled {
+                fail(&env, ContractError::WishAlreadyFulfilled);
+            }
+        }
+
+        let escrow_key = DataKey::Escrow(user.clone(), list, wish_id);
+        let escrow: Escrow = store.get(&escrow_key).unwrap_or_else(|| fail(&env, ContractError::EscrowNotFound));
+        if escrow.funder != funder {
+            fail(&env, ContractError::NotEscrowFunder);
+        }
+
+        token::Client::new(&env, &escrow.token).transfer(&env.current_contract_address(), &funder, &escrow.amount);
+        store.remove(&escrow_key);
+
+        EscrowReclaimedEvent {
+          user,
+          wish_id,
+          funder,
+          amount: escrow.amount,
+        }.publish(&env);
+    }
+
     /// View function to see a user's list
-    pub fn get_list(env: Env, user: Address) -> Vec<Wish> {
-        let key = DataKey::Wishes(user.clone());
-        let wishes = env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(&env));
+    pub fn get_list(env: Env, user: Address, list: String) -> Vec<Wish> {
+        let store = PersistentStore::new(&env);
+        let key = DataKey::Wishes(user.clone(), list);
+        let wishes = store.get(&key).unwrap_or_else(|| Vec::new(&env));
         // Even reading data requires bumping TTL to keep it alive!
-        bump_persistent_ttl(&env, &key); 
+        bump_persistent_ttl(&env, &key);
         wishes
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+    fn create_contract<'a>(
+        env: &Env,
+        committee: &Vec<Address>,
+        threshold: u32,
+        christmas_deadline: u64,
+        naughty_threshold: i128,
+    ) -> SeasonalWishlistClient<'a> {
+        let contract_id = env.register(
+            SeasonalWishlist,
+            (committee.clone(), threshold, christmas_deadline, naughty_threshold),
+        );
+        SeasonalWishlistClient::new(env, &contract_id)
+    }
+
+    // A Stellar asset contract behaves like any other token, so it stands in for the
+    // real gift-card/stablecoin tokens `fund_wish` would escrow on mainnet.
+    fn create_token<'a>(env: &Env, admin: &Address) -> (Address, token::Client<'a>, token::StellarAssetClient<'a>) {
+        let sac = env.register_stellar_asset_contract_v2(admin.clone());
+        let address = sac.address();
+        (address.clone(), token::Client::new(env, &address), token::StellarAssetClient::new(env, &address))
+    }
+
+    #[test]
+    fn fund_wish_then_mark_fulfilled_releases_escrow_to_the_user() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let signer = Address::generate(&env);
+        let committee = Vec::from_array(&env, [signer.clone()]);
+        let far_future = env.ledger().timestamp() + 1_000_000;
+        let client = create_contract(&env, &committee, 1, far_future, 100);
+
+        let user = Address::generate(&env);
+        let funder = Address::generate(&env);
+        let (token_address, token_client, token_admin) = create_token(&env, &signer);
+        token_admin.mint(&funder, &1_000);
+
+        let list = String::from_str(&env, "Main");
+        let wish_id = client.add_wish(&user, &list, &String::from_str(&env, "A bike"));
+        client.fund_wish(&funder, &user, &list, &wish_id, &token_address, &500, &Vec::new(&env));
+        assert_eq!(token_client.balance(&funder), 500);
+
+        let approvers = Vec::from_array(&env, [signer.clone()]);
+        client.mark_fulfilled(&approvers, &user, &list, &wish_id);
+
+        assert_eq!(token_client.balance(&user), 500);
+        assert!(client.get_list(&user, &list).get(0).unwrap().fulfilled);
+    }
+
+    #[test]
+    fn mark_fulfilled_refuses_to_release_until_its_witnesses_hold() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let signer = Address::generate(&env);
+        let committee = Vec::from_array(&env, [signer.clone()]);
+        let far_future = env.ledger().timestamp() + 1_000_000;
+        let client = create_contract(&env, &committee, 1, far_future, 100);
+
+        let user = Address::generate(&env);
+        let funder = Address::generate(&env);
+        let (token_address, _token_client, token_admin) = create_token(&env, &signer);
+        token_admin.mint(&funder, &1_000);
+
+        let list = String::from_str(&env, "Main");
+        let wish_id = client.add_wish(&user, &list, &String::from_str(&env, "A bike"));
+        let witnesses = Vec::from_array(&env, [Witness::Timestamp(env.ledger().timestamp() + 10_000)]);
+        client.fund_wish(&funder, &user, &list, &wish_id, &token_address, &500, &witnesses);
+
+        let approvers = Vec::from_array(&env, [signer.clone()]);
+        let result = client.try_mark_fulfilled(&approvers, &user, &list, &wish_id);
+        assert_eq!(result, Err(Ok(ContractError::WitnessesNotSatisfied)));
+    }
+
+    #[test]
+    fn reclaim_escrow_returns_funds_once_christmas_has_passed_unfulfilled() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let signer = Address::generate(&env);
+        let committee = Vec::from_array(&env, [signer.clone()]);
+        let deadline = env.ledger().timestamp() + 100;
+        let client = create_contract(&env, &committee, 1, deadline, 100);
+
+        let user = Address::generate(&env);
+        let funder = Address::generate(&env);
+        let (token_address, token_client, token_admin) = create_token(&env, &signer);
+        token_admin.mint(&funder, &1_000);
+
+        let list = String::from_str(&env, "Main");
+        let wish_id = client.add_wish(&user, &list, &String::from_str(&env, "A bike"));
+        client.fund_wish(&funder, &user, &list, &wish_id, &token_address, &500, &Vec::new(&env));
+
+        env.ledger().with_mut(|l| l.timestamp = deadline + 1);
+        client.reclaim_escrow(&funder, &user, &list, &wish_id);
+
+        assert_eq!(token_client.balance(&funder), 1_000);
+    }
+
+    #[test]
+    fn committee_actions_need_threshold_distinct_approvers() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let committee = Vec::from_array(&env, [a.clone(), b.clone()]);
+        let far_future = env.ledger().timestamp() + 1_000_000;
+        let client = create_contract(&env, &committee, 2, far_future, 100);
+
+        let one_approver = Vec::from_array(&env, [a.clone()]);
+        let result = client.try_set_threshold(&one_approver, &1);
+        assert_eq!(result, Err(Ok(ContractError::ThresholdNotMet)));
+
+        // Listing the same committee member twice doesn't let them count twice toward the threshold.
+        let duplicate_approver = Vec::from_array(&env, [a.clone(), a.clone()]);
+        let result = client.try_set_threshold(&duplicate_approver, &1);
+        assert_eq!(result, Err(Ok(ContractError::DuplicateApprover)));
+
+        let both_approvers = Vec::from_array(&env, [a.clone(), b.clone()]);
+        client.set_threshold(&both_approvers, &1);
+    }
+
+    #[test]
+    fn pardon_stops_a_stale_vote_from_corrupting_the_next_report_naughty() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let signer = Address::generate(&env);
+        let committee = Vec::from_array(&env, [signer.clone()]);
+        let far_future = env.ledger().timestamp() + 1_000_000;
+        let client = create_contract(&env, &committee, 1, far_future, 100);
+
+        let voter = Address::generate(&env);
+        let target = Address::generate(&env);
+        let approvers = Vec::from_array(&env, [signer.clone()]);
+        client.set_voter_weight(&approvers, &voter, &90);
+
+        client.report_naughty(&voter, &target, &80);
+        assert_eq!(client.naughty_score(&target), 80);
+
+        client.pardon(&approvers, &target);
+        assert_eq!(client.naughty_score(&target), 0);
+
+        // Before the epoch fix this landed on `0 - 80 + 90 = 10` instead of 90, because
+        // the voter's pre-pardon contribution was still live under the old vote key.
+        client.report_naughty(&voter, &target, &90);
+        assert_eq!(client.naughty_score(&target), 90);
+    }
+}
@@ -0,0 +1,90 @@
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val};
+
+use crate::DataKey;
+
+/// Abstracts storage access behind `get`/`set`/`has`/`remove`/`bump_ttl`, implemented by
+/// `PersistentStore` and `InstanceStore` below. The contract functions (`add_wish`,
+/// `mark_fulfilled`, `get_list`, ...) construct those concrete stores directly, so no other
+/// implementation (such as an in-memory map) can be passed in, and every `get`/`set` goes
+/// straight to Soroban storage with no batching of repeated access to the same key.
+pub trait Store {
+    fn get<V: TryFromVal<Env, Val>>(&self, key: &DataKey) -> Option<V>;
+    fn set<V: IntoVal<Env, Val>>(&self, key: &DataKey, value: &V);
+    fn has(&self, key: &DataKey) -> bool;
+    fn remove(&self, key: &DataKey);
+    fn bump_ttl(&self, key: &DataKey);
+}
+
+/// The real, Soroban-backed `Store`: every call goes straight to `env.storage().persistent()`.
+pub struct PersistentStore<'a> {
+    env: &'a Env,
+}
+
+impl<'a> PersistentStore<'a> {
+    pub fn new(env: &'a Env) -> Self {
+        Self { env }
+    }
+}
+
+impl<'a> Store for PersistentStore<'a> {
+    fn get<V: TryFromVal<Env, Val>>(&self, key: &DataKey) -> Option<V> {
+        self.env.storage().persistent().get(key)
+    }
+
+    fn set<V: IntoVal<Env, Val>>(&self, key: &DataKey, value: &V) {
+        self.env.storage().persistent().set(key, value);
+    }
+
+    fn has(&self, key: &DataKey) -> bool {
+        self.env.storage().persistent().has(key)
+    }
+
+    fn remove(&self, key: &DataKey) {
+        self.env.storage().persistent().remove(key);
+    }
+
+    fn bump_ttl(&self, key: &DataKey) {
+        self.env.storage().persistent().extend_ttl(key, 2_000, 5_000); // If < 2000 ledgers, bump to 5000
+    }
+}
+
+/// The instance-storage counterpart of `PersistentStore`, for contract-wide config
+/// (`Committee`, `Threshold`, `ChristmasDeadline`, ...) and per-user registries (`Lists`)
+/// that were living on `env.storage().instance()` directly. Instance TTL isn't keyed, so
+/// `bump_ttl` ignores its argument and bumps the whole instance, same as before.
+pub struct InstanceStore<'a> {
+    env: &'a Env,
+}
+
+impl<'a> InstanceStore<'a> {
+    pub fn new(env: &'a Env) -> Self {
+        Self { env }
+    }
+
+    // Instance TTL isn't keyed like persistent TTL, so this bumps the whole instance.
+    pub fn bump(&self) {
+        self.env.storage().instance().extend_ttl(2_000, 5_000); // If < 2000 ledgers, bump to 5000
+    }
+}
+
+impl<'a> Store for InstanceStore<'a> {
+    fn get<V: TryFromVal<Env, Val>>(&self, key: &DataKey) -> Option<V> {
+        self.env.storage().instance().get(key)
+    }
+
+    fn set<V: IntoVal<Env, Val>>(&self, key: &DataKey, value: &V) {
+        self.env.storage().instance().set(key, value);
+    }
+
+    fn has(&self, key: &DataKey) -> bool {
+        self.env.storage().instance().has(key)
+    }
+
+    fn remove(&self, key: &DataKey) {
+        self.env.storage().instance().remove(key);
+    }
+
+    fn bump_ttl(&self, _key: &DataKey) {
+        self.bump();
+    }
+}